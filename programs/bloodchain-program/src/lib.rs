@@ -1,198 +1,451 @@
 use anchor_lang::prelude::*;
-use solana_program::{
-    account_info::{next_account_info, AccountInfo},
-    entrypoint,
-    entrypoint::ProgramResult,
-    msg,
-    program_error::ProgramError,
-    pubkey::Pubkey, program_pack::IsInitialized,
+use anchor_lang::solana_program::sysvar::instructions::{
+    self, load_current_index_checked, load_instruction_at_checked,
 };
-use solana_program::program_pack::{Pack, Sealed};
-
-entrypoint!(process_instruction);
 
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
-#[derive(Debug, Default, PartialEq)]
-pub struct Donation {
-    donor_name: [u8; 32],
-    blood_type: [u8; 3],
-    date: u64,
+/// Upper bound on how many other instructions (before and after the
+/// current one) `verify_attestation` will inspect, so a transaction stuffed with unrelated instructions can't be
+/// used to blow the compute budget on introspection alone.
+pub const MAX_INSTRUCTIONS_SCANNED: usize = 16;
+
+/// Upper bound on the number of `Donation` records a single
+/// `batch_add_donations` call may import, so one transaction can't grow a
+/// donor's account without bound.
+pub const MAX_BATCH_SIZE: usize = 64;
+
+/// Program ID of the approved attestation/verifier program. Replace with
+/// the real deployed verifier once one exists.
+fn attester_program_id() -> Pubkey {
+    Pubkey::new_from_array([7u8; 32])
 }
 
-impl Sealed for Donation {}
+/// Errors returned by the access-control guards below and by the
+/// instructions that rely on them.
+#[error_code]
+pub enum BloodchainError {
+    #[msg("Signer is not the registered blood-bank authority for this account")]
+    Unauthorized,
+    #[msg("This donation-history account has been paused by its authority")]
+    ProgramPaused,
+    #[msg("No matching attestation instruction from an approved verifier was found in this transaction")]
+    MissingAttestation,
+    #[msg("Batch import must contain between 1 and MAX_BATCH_SIZE donations")]
+    InvalidBatchSize,
+}
 
-impl Pack for Donation {
-    const LEN: usize = 40;
+/// A single blood donation record. Borsh-derived so it can be embedded
+/// directly in `DonationHistory` and passed as a typed instruction
+/// argument instead of being sliced out of raw instruction bytes.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct Donation {
+    pub donor_name: String,
+    pub blood_type: [u8; 3],
+    pub date: u64,
+}
 
-    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
-        let donor_name = src[..32].try_into().map_err(|_| ProgramError::InvalidInstructionData)?;
-        let blood_type = src[32..35].try_into().map_err(|_| ProgramError::InvalidInstructionData)?;
-        let date = u64::from_le_bytes(src[35..].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+/// Program-wide donation counter, stored at the PDA derived from
+/// `[b"blood_bank"]`. A plain `#[account]` PDA rather than a singleton
+/// `#[state]` account, since `#[state]` was removed from anchor-lang long
+/// ago and isn't available in any current release.
+#[account]
+#[derive(Default)]
+pub struct BloodBank {
+    pub count: u64,
+    pub bump: u8,
+}
 
-        Ok(Donation {
-            donor_name,
-            blood_type,
-            date,
-        })
-    }
+/// Per-donor donation ledger, stored at the PDA derived from
+/// `[b"donation", donor_name.as_bytes()]`. Carries its own
+/// `authority`/`is_paused` header so the access-control guards can check
+/// it without touching the global `BloodBank` state, plus the canonical
+/// `bump` so later calls can re-derive the address cheaply instead of
+/// recomputing or passing it in.
+#[account]
+#[derive(Default)]
+pub struct DonationHistory {
+    pub authority: Pubkey,
+    pub is_paused: bool,
+    pub bump: u8,
+    pub donations: Vec<Donation>,
+}
 
-    fn pack_into_slice(&self, dst: &mut [u8]) {
-        dst[..32].copy_from_slice(&self.donor_name);
-        dst[32..35].copy_from_slice(&self.blood_type);
-        dst[35..].copy_from_slice(&self.date.to_le_bytes());
-    }
+/// Core comparison behind `is_registered_bank_*`, pulled out so it can be
+/// unit-tested without spinning up a full `Context`.
+fn authority_matches(stored_authority: Pubkey, signer: Pubkey) -> bool {
+    stored_authority == signer
 }
 
+/// Guard mirroring Anchor's `#[access_control(is_registered_bank_add(&ctx))]`
+/// pattern: rejects the instruction unless the transaction is signed by
+/// the blood bank recorded as this account's authority.
+fn is_registered_bank_add(ctx: &Context<AddDonation>) -> Result<()> {
+    require!(
+        authority_matches(ctx.accounts.donation_history.authority, ctx.accounts.authority.key()),
+        BloodchainError::Unauthorized
+    );
+    Ok(())
+}
 
-impl IsInitialized for Donation {
-    fn is_initialized(&self) -> bool {
-        // Implement initialization check logic
-        // ...
-        // Return true if the struct is properly initialized, false otherwise
-        true
+/// Guard mirroring Anchor's `#[access_control(...)]` pattern: rejects the
+/// instruction if the authority has paused this donation-history account.
+/// Stacks alongside `is_registered_bank_add` so operators can compose checks.
+fn not_paused(ctx: &Context<AddDonation>) -> Result<()> {
+    if ctx.accounts.donation_history.is_paused {
+        return err!(BloodchainError::ProgramPaused);
     }
+    Ok(())
 }
 
-#[program]
-pub mod bloodchain_program {
-    use super::*;
+/// Same check as `is_registered_bank_add`, for the `RotateAuthority`
+/// accounts (no realloc, so it needs its own `Context` type).
+fn is_registered_bank_rotate(ctx: &Context<RotateAuthority>) -> Result<()> {
+    require!(
+        authority_matches(ctx.accounts.donation_history.authority, ctx.accounts.authority.key()),
+        BloodchainError::Unauthorized
+    );
+    Ok(())
+}
 
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        Ok(())
-    }
+/// Same check as `is_registered_bank_add`, for the `BatchAddDonations`
+/// accounts (its own `Context` type, since the realloc sizing differs).
+fn is_registered_bank_batch(ctx: &Context<BatchAddDonations>) -> Result<()> {
+    require!(
+        authority_matches(ctx.accounts.donation_history.authority, ctx.accounts.authority.key()),
+        BloodchainError::Unauthorized
+    );
+    Ok(())
 }
 
-fn process_instruction(
-    _program_id: &Pubkey,
-    accounts: &[AccountInfo],
-    instruction_data: &[u8],
-) -> ProgramResult {
-    if instruction_data.is_empty() {
-        msg!("Instruction data is empty");
-        return Err(ProgramError::InvalidInstructionData);
+/// Same check as `not_paused`, for the `BatchAddDonations` accounts.
+fn not_paused_batch(ctx: &Context<BatchAddDonations>) -> Result<()> {
+    if ctx.accounts.donation_history.is_paused {
+        return err!(BloodchainError::ProgramPaused);
     }
+    Ok(())
+}
 
-    // Parse the instruction data and perform actions based on the instruction type
-    match instruction_data[0] {
-        0 => add_donation(accounts, &instruction_data[1..]),
-        1 => retrieve_donation_history(accounts),
-        _ => {
-            msg!("Invalid instruction");
-            Err(ProgramError::InvalidInstructionData)
-        }
+/// Number of instructions compiled into the current transaction message,
+/// per the Instructions sysvar's own wire format (a leading `u16` count).
+/// `solana_program` doesn't expose a typed accessor for this, so it's read
+/// directly the way other introspection helpers in the ecosystem do.
+fn total_instruction_count(instructions_sysvar: &AccountInfo) -> Result<usize> {
+    let data = instructions_sysvar.try_borrow_data().map_err(|_| error!(BloodchainError::MissingAttestation))?;
+    require!(data.len() >= 2, BloodchainError::MissingAttestation);
+    Ok(u16::from_le_bytes([data[0], data[1]]) as usize)
+}
+
+/// Which instruction indices `verify_attestation` should inspect: a
+/// window of up to `MAX_INSTRUCTIONS_SCANNED` indices centered on
+/// `current_index` (excluding `current_index` itself), clamped to
+/// `[0, total_instructions)`. A flat `0..MAX_INSTRUCTIONS_SCANNED` prefix
+/// would miss an attestation placed after `add_donation` in any
+/// transaction with more than `MAX_INSTRUCTIONS_SCANNED` instructions
+/// total, so the bound is taken relative to where `add_donation` actually
+/// sits in the message instead. Split out from `verify_attestation` so
+/// the windowing arithmetic can be unit-tested without a real sysvar
+/// account.
+fn attestation_scan_indices(current_index: usize, total_instructions: usize) -> Vec<usize> {
+    if total_instructions == 0 {
+        return Vec::new();
     }
+    let half_window = MAX_INSTRUCTIONS_SCANNED / 2;
+    let start = current_index.saturating_sub(half_window);
+    let end = current_index.saturating_add(half_window).min(total_instructions - 1);
+    (start..=end).filter(|&i| i != current_index).collect()
 }
 
-fn add_donation(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
-    // Ensure the accounts are provided
-    if accounts.is_empty() {
-        msg!("No accounts provided");
-        return Err(ProgramError::NotEnoughAccountKeys);
+/// Confirms, via the Instructions sysvar, that this transaction also
+/// carries an attestation instruction from an allow-listed verifier
+/// program for this exact `donor_name`/`date`, within
+/// `MAX_INSTRUCTIONS_SCANNED / 2` instructions before or after
+/// `add_donation` (see `attestation_scan_indices`); an attestation further
+/// away in the message is not found. Introspection only ever sees
+/// the top-level transaction message, so when `add_donation` is itself
+/// invoked via CPI this check cannot see instructions the outer
+/// transaction doesn't carry directly — callers that CPI into
+/// `add_donation` are responsible for arranging the attestation
+/// themselves.
+fn verify_attestation(instructions_sysvar: &AccountInfo, donor_name: &str, date: u64) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+    let total_instructions = total_instruction_count(instructions_sysvar)?;
+    let expected_data = attestation_payload(donor_name, date);
+
+    for i in attestation_scan_indices(current_index, total_instructions) {
+        let ix = load_instruction_at_checked(i, instructions_sysvar)?;
+        if ix.program_id == attester_program_id() && ix.data == expected_data {
+            return Ok(());
+        }
     }
 
-    // Retrieve the account info for the blood donation history state account
-    let accounts_iter = &mut accounts.iter();
-    let blood_donation_account = next_account_info(accounts_iter)?;
+    err!(BloodchainError::MissingAttestation)
+}
 
-    // Deserialize the donation data
-    let donation: Donation = unpack_donation(data)?;
+fn attestation_payload(donor_name: &str, date: u64) -> Vec<u8> {
+    let mut payload = donor_name.as_bytes().to_vec();
+    payload.extend_from_slice(&date.to_le_bytes());
+    payload
+}
 
-    // Update the blood donation history with the new donation
-    let mut blood_donation_history = get_donation_history(blood_donation_account)?;
-    blood_donation_history.push(donation);
+/// Borsh-serialized size of one `Donation` (4-byte string length prefix +
+/// the name bytes, then the fixed `blood_type`/`date` fields), used to size
+/// the `donation_history` realloc for an incoming batch up front.
+fn donation_space(donation: &Donation) -> usize {
+    4 + donation.donor_name.len() + 3 + 8
+}
 
-    // Serialize and save the updated blood donation history to the account data
-    let blood_donation_history_data = pack_donation_history(&blood_donation_history)?;
-    blood_donation_account.data.borrow_mut().copy_from_slice(&blood_donation_history_data);
+fn batch_additional_space(donations: &[Donation]) -> usize {
+    donations.iter().map(donation_space).sum()
+}
 
-    msg!("Donation added successfully");
-    Ok(())
+/// Derives the PDA (and canonical bump) for a donor's `DonationHistory`
+/// account, mirroring the `seeds = [b"donation", donor_name.as_bytes()]`
+/// constraint on the accounts below. Exposed so off-chain clients don't
+/// have to hardcode the seed scheme.
+pub fn donation_history_pda(program_id: &Pubkey, donor_name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"donation", donor_name.as_bytes()], program_id)
 }
 
-fn retrieve_donation_history(accounts: &[AccountInfo]) -> ProgramResult {
-    // Ensure the accounts are provided
-    if accounts.is_empty() {
-        msg!("No accounts provided");
-        return Err(ProgramError::NotEnoughAccountKeys);
+#[program]
+pub mod bloodchain_program {
+    use super::*;
+
+    pub fn initialize_donation_history(
+        ctx: Context<InitializeDonationHistory>,
+        _donor_name: String,
+        authority: Pubkey,
+    ) -> Result<()> {
+        let donation_history = &mut ctx.accounts.donation_history;
+        donation_history.authority = authority;
+        donation_history.is_paused = false;
+        donation_history.bump = *ctx.bumps.get("donation_history").unwrap();
+        Ok(())
     }
 
-    // Retrieve the account info for the blood donation history state account
-    let accounts_iter = &mut accounts.iter();
-    let blood_donation_account = next_account_info(accounts_iter)?;
+    pub fn initialize_blood_bank(ctx: Context<InitializeBloodBank>) -> Result<()> {
+        let blood_bank = &mut ctx.accounts.blood_bank;
+        blood_bank.count = 0;
+        blood_bank.bump = *ctx.bumps.get("blood_bank").unwrap();
+        Ok(())
+    }
 
-    // Read the blood donation history from the account
-    let blood_donation_history = get_donation_history(blood_donation_account)?;
+    #[access_control(is_registered_bank_add(&ctx), not_paused(&ctx))]
+    pub fn add_donation(ctx: Context<AddDonation>, donor_name: String, donation: Donation) -> Result<()> {
+        verify_attestation(&ctx.accounts.instructions, &donor_name, donation.date)?;
 
-    msg!("Blood Donation History:");
-    for (index, donation) in blood_donation_history.iter().enumerate() {
-        msg!(
-            "Donation {}: Donor Name: {:?}, Blood Type: {:?}, Date: {}",
-            index + 1,
-            String::from_utf8_lossy(&donation.donor_name),
-            String::from_utf8_lossy(&donation.blood_type),
-            donation.date
-        );
+        ctx.accounts.donation_history.donations.push(donation);
+        ctx.accounts.blood_bank.count += 1;
+        msg!("Donation added successfully");
+        Ok(())
     }
 
-    Ok(())
-}
+    #[access_control(is_registered_bank_rotate(&ctx))]
+    pub fn rotate_authority(ctx: Context<RotateAuthority>, _donor_name: String, new_authority: Pubkey) -> Result<()> {
+        ctx.accounts.donation_history.authority = new_authority;
+        msg!("Authority rotated successfully");
+        Ok(())
+    }
 
-    // Helper function to deserialize donation data
-fn unpack_donation(data: &[u8]) -> Result<Donation, ProgramError> {
-    let donor_name: [u8; 32] = data[..32].try_into().map_err(|_| ProgramError::InvalidInstructionData)?;
-    let blood_type: [u8; 3] = data[32..35].try_into().map_err(|_| ProgramError::InvalidInstructionData)?;
-    let date = u64::from_le_bytes(data[35..].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+    pub fn retrieve(ctx: Context<ReadDonations>, _donor_name: String) -> Result<Vec<Donation>> {
+        Ok(ctx.accounts.donation_history.donations.clone())
+    }
 
-    Ok(Donation {
-        donor_name,
-        blood_type,
-        date,
-    })
-}
+    /// Imports a batch of donations in one call. `donations` is decoded
+    /// by Anchor/Borsh's length-prefixed `Vec` deserialization before this
+    /// handler runs, so malformed bytes never reach it; the handler then
+    /// rejects an empty batch or one larger than `MAX_BATCH_SIZE` before
+    /// appending anything. The realloc on `BatchAddDonations` sizes the
+    /// account for the whole batch up front rather than growing it one
+    /// donation at a time.
+    #[access_control(is_registered_bank_batch(&ctx), not_paused_batch(&ctx))]
+    pub fn batch_add_donations(
+        ctx: Context<BatchAddDonations>,
+        donor_name: String,
+        donations: Vec<Donation>,
+    ) -> Result<()> {
+        require!(
+            !donations.is_empty() && donations.len() <= MAX_BATCH_SIZE,
+            BloodchainError::InvalidBatchSize
+        );
 
-// Helper function to serialize donation history
-fn pack_donation_history(history: &[Donation]) -> Result<Vec<u8>, ProgramError> {
-    let mut result = Vec::new();
-    for donation in history {
-        result.extend_from_slice(&donation.donor_name);
-        result.extend_from_slice(&donation.blood_type);
-        result.extend_from_slice(&donation.date.to_le_bytes());
+        let imported = donations.len() as u64;
+        ctx.accounts.donation_history.donations.extend(donations);
+        ctx.accounts.blood_bank.count += imported;
+        msg!("Imported {} donations for {}", imported, donor_name);
+        Ok(())
     }
-    Ok(result)
 }
 
-// Helper function to get the current donation history from an account
-fn get_donation_history(account: &AccountInfo) -> Result<Vec<Donation>, ProgramError> {
-    let data = &account.data.borrow();
-    let mut history = Vec::new();
-    let mut index = 0;
-    while index < data.len() {
-        let donation_data = &data[index..(index + Donation::LEN)];
-        let donation = Donation::unpack(donation_data)?;
-        history.push(donation);
-        index += Donation::LEN;
-    }
-    Ok(history)
+#[derive(Accounts)]
+pub struct InitializeBloodBank<'info> {
+    #[account(init, payer = user, space = 8 + 8 + 1, seeds = [b"blood_bank"], bump)]
+    pub blood_bank: Account<'info, BloodBank>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-#[cfg(test)]
-mod tests {
-    // Add unit tests
+#[derive(Accounts)]
+#[instruction(donor_name: String)]
+pub struct InitializeDonationHistory<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = 8 + 32 + 1 + 1 + 4,
+        seeds = [b"donation", donor_name.as_bytes()],
+        bump,
+    )]
+    pub donation_history: Account<'info, DonationHistory>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(init, payer = user, space = 40)]
-    blood_donation_account: Account<'info, Donation>,
-    user: Signer<'info>,
-    system_program: Program<'info, System>,
+#[instruction(donor_name: String, donation: Donation)]
+pub struct AddDonation<'info> {
+    #[account(
+        mut,
+        seeds = [b"donation", donor_name.as_bytes()],
+        bump = donation_history.bump,
+        realloc = donation_history.to_account_info().data_len() + donation_space(&donation),
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub donation_history: Account<'info, DonationHistory>,
+    #[account(mut, seeds = [b"blood_bank"], bump = blood_bank.bump)]
+    pub blood_bank: Account<'info, BloodBank>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: the `address` constraint pins this to the Instructions
+    /// sysvar; `add_donation` uses it purely for introspection.
+    #[account(address = instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
 }
 
-pub fn initialize(ctx: Context<Initialize>) -> Result<(), ProgramError> {
-    let blood_donation_account = &mut ctx.accounts.blood_donation_account;
-    blood_donation_account.is_initialized = true;
-    // Other initialization logic
-    Ok(())
+#[derive(Accounts)]
+#[instruction(donor_name: String)]
+pub struct RotateAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"donation", donor_name.as_bytes()],
+        bump = donation_history.bump,
+    )]
+    pub donation_history: Account<'info, DonationHistory>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(donor_name: String, donations: Vec<Donation>)]
+pub struct BatchAddDonations<'info> {
+    #[account(
+        mut,
+        seeds = [b"donation", donor_name.as_bytes()],
+        bump = donation_history.bump,
+        realloc = donation_history.to_account_info().data_len() + batch_additional_space(&donations),
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub donation_history: Account<'info, DonationHistory>,
+    #[account(mut, seeds = [b"blood_bank"], bump = blood_bank.bump)]
+    pub blood_bank: Account<'info, BloodBank>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+#[instruction(donor_name: String)]
+pub struct ReadDonations<'info> {
+    #[account(
+        seeds = [b"donation", donor_name.as_bytes()],
+        bump = donation_history.bump,
+    )]
+    pub donation_history: Account<'info, DonationHistory>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn authority_matches_same_key() {
+        let key = Pubkey::new_from_array([1u8; 32]);
+        assert!(authority_matches(key, key));
+    }
+
+    #[test]
+    fn authority_matches_rejects_different_key() {
+        let stored = Pubkey::new_from_array([1u8; 32]);
+        let signer = Pubkey::new_from_array([2u8; 32]);
+        assert!(!authority_matches(stored, signer));
+    }
+
+    #[test]
+    fn donation_history_pda_is_deterministic_and_unique_per_donor() {
+        let program_id = Pubkey::new_from_array([9u8; 32]);
+        let (pda_a, bump_a) = donation_history_pda(&program_id, "alice");
+        let (pda_a_again, bump_a_again) = donation_history_pda(&program_id, "alice");
+        assert_eq!(pda_a, pda_a_again);
+        assert_eq!(bump_a, bump_a_again);
+
+        let (pda_bob, _) = donation_history_pda(&program_id, "bob");
+        assert_ne!(pda_a, pda_bob);
+    }
+
+    #[test]
+    fn attestation_payload_encodes_name_and_date() {
+        let payload = attestation_payload("alice", 42);
+        assert_eq!(payload, [b"alice".as_slice(), &42u64.to_le_bytes()].concat());
+    }
+
+    #[test]
+    fn attestation_scan_indices_excludes_current_index() {
+        let indices = attestation_scan_indices(5, 10);
+        assert!(!indices.contains(&5));
+    }
+
+    #[test]
+    fn attestation_scan_indices_covers_instructions_past_max_scanned_when_near_current_index() {
+        // Regression test: a transaction with far more than
+        // MAX_INSTRUCTIONS_SCANNED instructions, where add_donation (and
+        // thus current_index) sits well past the old flat 0..16 prefix.
+        // The window must follow current_index, not just the front of
+        // the message.
+        let total_instructions = 100;
+        let current_index = 50;
+        let indices = attestation_scan_indices(current_index, total_instructions);
+
+        assert!(indices.iter().any(|&i| i > current_index));
+        assert!(indices.iter().any(|&i| i < current_index));
+        assert!(indices.len() <= MAX_INSTRUCTIONS_SCANNED);
+    }
+
+    #[test]
+    fn attestation_scan_indices_clamps_to_total_instructions() {
+        let indices = attestation_scan_indices(2, 5);
+        assert!(indices.iter().all(|&i| i < 5));
+    }
+
+    #[test]
+    fn donation_space_accounts_for_variable_length_name() {
+        let short = Donation { donor_name: "al".into(), blood_type: *b"O+ ", date: 0 };
+        let long = Donation { donor_name: "alexandra".into(), blood_type: *b"O+ ", date: 0 };
+        assert_eq!(donation_space(&short), 4 + 2 + 3 + 8);
+        assert!(donation_space(&long) > donation_space(&short));
+    }
+
+    #[test]
+    fn batch_additional_space_sums_each_donation() {
+        let donations = vec![
+            Donation { donor_name: "a".into(), blood_type: *b"O+ ", date: 0 },
+            Donation { donor_name: "bb".into(), blood_type: *b"A- ", date: 0 },
+        ];
+        let expected: usize = donations.iter().map(donation_space).sum();
+        assert_eq!(batch_additional_space(&donations), expected);
+    }
+}